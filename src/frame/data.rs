@@ -1,4 +1,6 @@
-use super::{IdentifierFormat, STANDARD_MASK, EXTENDED_MASK};
+use embedded_can::{ExtendedId, Id, StandardId};
+
+use super::{CanId, IdentifierFormat, STANDARD_MASK, EXTENDED_MASK};
 
 #[derive(Debug)]
 pub struct DataFrame {
@@ -6,9 +8,31 @@ pub struct DataFrame {
     identifier_format: IdentifierFormat,
     dlc: u8,
     data: [u8; 8],
-    timestamp: u16
+    timestamp: Option<u16>
+}
+
+/// Errors from the validated in-place setters on [`DataFrame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFrameMutationError {
+    /// The data length code is greater than 8, the most data bytes a
+    /// classic CAN frame carries.
+    DlcOutOfRange,
+    /// The replacement data's length does not match the frame's current
+    /// [`DataFrame::dlc`].
+    DataLenMismatch
+}
+
+impl std::fmt::Display for DataFrameMutationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataFrameMutationError::DlcOutOfRange => write!(f, "data length code must be 0..=8"),
+            DataFrameMutationError::DataLenMismatch => write!(f, "replacement data does not match the frame's dlc"),
+        }
+    }
 }
 
+impl std::error::Error for DataFrameMutationError {}
+
 impl DataFrame {
     pub fn new() -> DataFrameBuilder {
         DataFrameBuilder::new()
@@ -39,9 +63,43 @@ impl DataFrame {
         &mut self.data[..len]
     }
 
-    pub fn timestamp(&self) -> u16 {
+    /// The millisecond timestamp the frame arrived with, or `None` if the
+    /// record it was decoded from didn't carry one (the adapter's `Z1`
+    /// timestamp mode was off).
+    pub fn timestamp(&self) -> Option<u16> {
         self.timestamp
     }
+
+    /// Replaces the identifier in place, re-validating it against `format`
+    /// the same way [`CanId::new`] does rather than silently truncating it.
+    pub fn set_can_id(&mut self, can_id: u32, format: IdentifierFormat) -> Result<(), super::CanIdError> {
+        let validated = CanId::new(can_id, format)?;
+        self.can_id = validated.value();
+        self.identifier_format = validated.identifier_format();
+        Ok(())
+    }
+
+    /// Replaces the data length code in place. Existing data bytes beyond
+    /// the new length are left in the backing array but no longer
+    /// observable through [`DataFrame::data`].
+    pub fn set_dlc(&mut self, dlc: u8) -> Result<(), DataFrameMutationError> {
+        if dlc > 8 {
+            return Err(DataFrameMutationError::DlcOutOfRange);
+        }
+        self.dlc = dlc;
+        Ok(())
+    }
+
+    /// Replaces the data payload in place. `data` must be exactly
+    /// [`DataFrame::dlc`] bytes long; use [`DataFrame::set_dlc`] first to
+    /// change the length.
+    pub fn set_data(&mut self, data: &[u8]) -> Result<(), DataFrameMutationError> {
+        if data.len() != self.dlc as usize {
+            return Err(DataFrameMutationError::DataLenMismatch);
+        }
+        self.data[..data.len()].copy_from_slice(data);
+        Ok(())
+    }
 }
 
 pub struct DataFrameBuilder {
@@ -49,7 +107,7 @@ pub struct DataFrameBuilder {
     identifier_format: IdentifierFormat,
     dlc: u8,
     data: [u8; 8],
-    timestamp: u16
+    timestamp: Option<u16>
 }
 
 impl DataFrameBuilder {
@@ -59,10 +117,17 @@ impl DataFrameBuilder {
             identifier_format: IdentifierFormat::Standard,
             dlc: 0,
             data: [0u8; 8],
-            timestamp: 0
+            timestamp: None
         }
     }
 
+    /// Sets the millisecond timestamp the frame carries (0..=0xEA5F,
+    /// wrapping at 60000), the adapter's `Z1` timestamp mode counter.
+    pub fn timestamp(mut self, timestamp: u16) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
     pub fn can_id(mut self, can_id: u32, format: IdentifierFormat) -> Self {
         match format {
             IdentifierFormat::Standard => {
@@ -147,3 +212,258 @@ impl From<DataFrameBuilder> for DataFrame {
         }
     }
 }
+
+impl embedded_can::Frame for DataFrame {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if data.len() > 8 {
+            return None;
+        }
+
+        let builder = match id.into() {
+            Id::Standard(id) => DataFrameBuilder::new().can_id(id.as_raw() as u32, IdentifierFormat::Standard),
+            Id::Extended(id) => DataFrameBuilder::new().can_id(id.as_raw(), IdentifierFormat::Extended),
+        };
+
+        Some(builder.dlc(data.len() as u8).data(data).into())
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        // A DataFrame always carries a payload; remote-transmission
+        // requests are [`super::RemoteFrame`]'s job.
+        None
+    }
+
+    fn is_extended(&self) -> bool {
+        matches!(self.identifier_format, IdentifierFormat::Extended)
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        match self.identifier_format {
+            IdentifierFormat::Standard => Id::Standard(StandardId::new(self.can_id() as u16).unwrap()),
+            IdentifierFormat::Extended => Id::Extended(ExtendedId::new(self.can_id()).unwrap()),
+        }
+    }
+
+    fn dlc(&self) -> usize {
+        self.dlc as usize
+    }
+
+    fn data(&self) -> &[u8] {
+        self.data()
+    }
+}
+
+/// Errors from decoding a [`DataFrame`] off the wire (`t`/`T` records).
+#[derive(Debug, PartialEq)]
+pub enum DataFrameParseError {
+    /// The record did not have the length implied by its command byte and
+    /// DLC, once an optional 4 hex digit timestamp is accounted for.
+    InvalidSize,
+    MessageStartError,
+    IntegerParseError,
+    Utf8Error,
+    DlcError,
+    MessageTerminationError
+}
+
+impl std::fmt::Display for DataFrameParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataFrameParseError::InvalidSize => write!(f, "record has an invalid size for its data length code"),
+            DataFrameParseError::MessageStartError => write!(f, "record does not start with 't' or 'T'"),
+            DataFrameParseError::IntegerParseError => write!(f, "record contains a field that is not valid hexadecimal"),
+            DataFrameParseError::Utf8Error => write!(f, "record is not valid ASCII/UTF-8"),
+            DataFrameParseError::DlcError => write!(f, "record has an invalid data length code"),
+            DataFrameParseError::MessageTerminationError => write!(f, "record is not terminated with '\\r'"),
+        }
+    }
+}
+
+impl std::error::Error for DataFrameParseError {}
+
+impl TryFrom<&[u8]> for DataFrame {
+    type Error = DataFrameParseError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let command = *value.first().ok_or(DataFrameParseError::MessageStartError)?;
+        let identifier_format = match command {
+            b't' => IdentifierFormat::Standard,
+            b'T' => IdentifierFormat::Extended,
+            _ => return Err(DataFrameParseError::MessageStartError)
+        };
+
+        let id_width = match identifier_format {
+            IdentifierFormat::Standard => 3,
+            IdentifierFormat::Extended => 8
+        };
+
+        let id_slice = value.get(1..1+id_width).ok_or(DataFrameParseError::InvalidSize)?;
+        let id_str = std::str::from_utf8(id_slice).map_err(|_| DataFrameParseError::Utf8Error)?;
+        let can_id = u32::from_str_radix(id_str, 16).map_err(|_| DataFrameParseError::IntegerParseError)?;
+
+        let dlc_index = 1 + id_width;
+        let dlc_byte = *value.get(dlc_index).ok_or(DataFrameParseError::InvalidSize)?;
+        let dlc = (dlc_byte as char).to_digit(16).ok_or(DataFrameParseError::DlcError)? as u8;
+        if dlc > 8 {
+            return Err(DataFrameParseError::DlcError);
+        }
+
+        // layout: command + id + dlc nibble, then the data payload, then an
+        // optional trailing 4 hex digit timestamp, then the terminator.
+        let data_start = dlc_index + 1;
+        let payload_end = data_start + 2 * dlc as usize;
+
+        let terminator_index = value.len().checked_sub(1).ok_or(DataFrameParseError::InvalidSize)?;
+        if value[terminator_index] != b'\r' {
+            return Err(DataFrameParseError::MessageTerminationError);
+        }
+
+        let residual = terminator_index.checked_sub(payload_end).ok_or(DataFrameParseError::InvalidSize)?;
+        let timestamp = match residual {
+            0 => None,
+            4 => {
+                let slice = &value[payload_end..payload_end+4];
+                let text = std::str::from_utf8(slice).map_err(|_| DataFrameParseError::Utf8Error)?;
+                Some(u16::from_str_radix(text, 16).map_err(|_| DataFrameParseError::IntegerParseError)?)
+            },
+            _ => return Err(DataFrameParseError::InvalidSize)
+        };
+
+        let data_slice = &value[data_start..payload_end];
+        let mut data = [0u8; 8];
+        for i in 0..dlc as usize {
+            let byte_str = std::str::from_utf8(&data_slice[2*i..2*i+2]).map_err(|_| DataFrameParseError::Utf8Error)?;
+            data[i] = u8::from_str_radix(byte_str, 16).map_err(|_| DataFrameParseError::IntegerParseError)?;
+        }
+
+        Ok(DataFrame { can_id, identifier_format, dlc, data, timestamp })
+    }
+}
+
+impl std::fmt::Display for DataFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.identifier_format {
+            IdentifierFormat::Standard => write!(f, "t{:03X}{:01X}", self.can_id(), self.dlc)?,
+            IdentifierFormat::Extended => write!(f, "T{:08X}{:01X}", self.can_id(), self.dlc)?,
+        }
+
+        for byte in self.data() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        if let Some(timestamp) = self.timestamp {
+            write!(f, "{:04X}", timestamp)?;
+        }
+
+        write!(f, "\r")
+    }
+}
+
+impl std::str::FromStr for DataFrame {
+    type Err = DataFrameParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DataFrame::try_from(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_frame_round_trips_through_display_and_try_from() {
+        let frame: DataFrame = DataFrame::new()
+            .can_id(0x123, IdentifierFormat::Standard)
+            .dlc(2)
+            .data(&[0xDE, 0xAD])
+            .into();
+
+        let text = frame.to_string();
+        assert_eq!(text, "t1232DEAD\r");
+
+        let parsed = DataFrame::try_from(text.as_bytes()).unwrap();
+        assert_eq!(parsed.can_id(), 0x123);
+        assert_eq!(parsed.data(), &[0xDE, 0xAD]);
+        assert_eq!(parsed.timestamp(), None);
+    }
+
+    #[test]
+    fn extended_frame_with_timestamp_round_trips() {
+        let frame: DataFrame = DataFrame::new()
+            .can_id(0x1ABCDEF, IdentifierFormat::Extended)
+            .dlc(1)
+            .data(&[0x42])
+            .timestamp(0xEA5F)
+            .into();
+
+        let text = frame.to_string();
+        assert_eq!(text, "T01ABCDEF142EA5F\r");
+
+        let parsed = DataFrame::try_from(text.as_bytes()).unwrap();
+        assert_eq!(parsed.can_id(), 0x1ABCDEF);
+        assert_eq!(parsed.timestamp(), Some(0xEA5F));
+    }
+
+    #[test]
+    fn try_from_rejects_unknown_command() {
+        assert_eq!(DataFrame::try_from(b"x1230\r".as_slice()), Err(DataFrameParseError::MessageStartError));
+    }
+
+    #[test]
+    fn try_from_rejects_missing_terminator() {
+        assert_eq!(DataFrame::try_from(b"t1230".as_slice()), Err(DataFrameParseError::MessageTerminationError));
+    }
+
+    #[test]
+    fn try_from_rejects_dlc_over_eight() {
+        assert_eq!(DataFrame::try_from(b"t123F\r".as_slice()), Err(DataFrameParseError::DlcError));
+    }
+
+    #[test]
+    fn embedded_can_new_rejects_oversized_data() {
+        use embedded_can::{Frame as _, StandardId, Id};
+        assert!(DataFrame::new(Id::Standard(StandardId::new(0x123).unwrap()), &[0u8; 9]).is_none());
+    }
+
+    #[test]
+    fn embedded_can_new_remote_is_always_none() {
+        use embedded_can::{Frame as _, StandardId, Id};
+        assert!(DataFrame::new_remote(Id::Standard(StandardId::new(0x123).unwrap()), 0).is_none());
+    }
+
+    #[test]
+    fn from_str_matches_try_from() {
+        let frame: DataFrame = "t1230\r".parse().unwrap();
+        assert_eq!(frame.can_id(), 0x123);
+        assert_eq!(frame.dlc(), 0);
+    }
+
+    #[test]
+    fn set_can_id_validates_against_the_identifier_format() {
+        let mut frame: DataFrame = DataFrame::new().can_id(0x123, IdentifierFormat::Standard).into();
+        assert_eq!(frame.set_can_id(0x7FF, IdentifierFormat::Standard), Ok(()));
+        assert_eq!(frame.can_id(), 0x7FF);
+        assert!(frame.set_can_id(0x800, IdentifierFormat::Standard).is_err());
+        assert_eq!(frame.can_id(), 0x7FF);
+    }
+
+    #[test]
+    fn set_dlc_rejects_values_over_eight() {
+        let mut frame: DataFrame = DataFrame::new().can_id(0x123, IdentifierFormat::Standard).dlc(2).into();
+        assert_eq!(frame.set_dlc(9), Err(DataFrameMutationError::DlcOutOfRange));
+        assert_eq!(frame.dlc(), 2);
+    }
+
+    #[test]
+    fn set_data_requires_matching_dlc() {
+        let mut frame: DataFrame = DataFrame::new().can_id(0x123, IdentifierFormat::Standard).dlc(2).data(&[0, 0]).into();
+        assert_eq!(frame.set_data(&[0xDE, 0xAD]), Ok(()));
+        assert_eq!(frame.data(), &[0xDE, 0xAD]);
+        assert_eq!(frame.set_data(&[0x01]), Err(DataFrameMutationError::DataLenMismatch));
+    }
+}
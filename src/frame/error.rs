@@ -0,0 +1,100 @@
+use super::IdentifierFormat;
+
+/// A bus error frame, reported by the adapter instead of a regular data or
+/// remote frame when the CAN controller observes an error condition.
+#[derive(Debug, Clone)]
+pub struct ErrorFrame {
+    can_id: u32,
+    identifier_format: IdentifierFormat,
+    dlc: u8,
+    data: [u8; 8],
+    timestamp: u16
+}
+
+impl ErrorFrame {
+    pub fn new() -> ErrorFrameBuilder {
+        ErrorFrameBuilder::new()
+    }
+
+    pub fn can_id(&self) -> u32 {
+        self.can_id
+    }
+
+    pub fn identifier_format(&self) -> IdentifierFormat {
+        self.identifier_format.clone()
+    }
+
+    pub fn dlc(&self) -> u8 {
+        self.dlc
+    }
+
+    pub fn data(&self) -> &[u8] {
+        let len = self.dlc as usize;
+        &self.data[..len]
+    }
+
+    pub fn mut_data(&mut self) -> &mut [u8] {
+        let len = self.dlc as usize;
+        &mut self.data[..len]
+    }
+
+    pub fn timestamp(&self) -> u16 {
+        self.timestamp
+    }
+}
+
+pub struct ErrorFrameBuilder {
+    can_id: u32,
+    identifier_format: IdentifierFormat,
+    dlc: u8,
+    data: [u8; 8],
+    timestamp: u16
+}
+
+impl ErrorFrameBuilder {
+    pub fn new() -> Self {
+        ErrorFrameBuilder {
+            can_id: 0,
+            identifier_format: IdentifierFormat::Standard,
+            dlc: 0,
+            data: [0u8; 8],
+            timestamp: 0
+        }
+    }
+
+    pub fn can_id(mut self, can_id: u32, format: IdentifierFormat) -> Self {
+        self.can_id = can_id;
+        self.identifier_format = format;
+        self
+    }
+
+    pub fn dlc(mut self, dlc: u8) -> Self {
+        self.dlc = dlc;
+        self
+    }
+
+    pub fn data(mut self, data: &[u8]) -> Self {
+        let len = if data.len() > 8 {
+            8
+        } else {
+            data.len()
+        };
+
+        for i in 0..len {
+            self.data[i] = data[i]
+        }
+        self
+    }
+}
+
+impl From<ErrorFrameBuilder> for ErrorFrame {
+    fn from(value: ErrorFrameBuilder) -> Self {
+        ErrorFrame {
+            can_id: value.can_id,
+            identifier_format: value.identifier_format,
+            dlc: value.dlc,
+            data: value.data,
+            timestamp: value.timestamp
+        }
+    }
+}
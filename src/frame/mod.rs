@@ -1,10 +1,14 @@
 pub mod data;
 pub mod remote;
+pub mod error;
 
 use std::ops::Rem;
 
-pub use remote::RemoteFrame;
-pub use data::{DataFrame, DataFrameParseError};
+use embedded_can::{ExtendedId, Id, StandardId};
+
+pub use remote::{RemoteFrame, RemoteFrameParseError, RemoteFrameDlcError};
+pub use data::{DataFrame, DataFrameParseError, DataFrameMutationError};
+pub use error::ErrorFrame;
 
 pub const STANDARD_MASK: u32 = 0x7FF;
 pub const EXTENDED_MASK: u32 = 0x1FFFFFFF;
@@ -15,16 +19,67 @@ pub enum FrameType {
     RemoteFrame
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IdentifierFormat {
     Standard,
     Extended
 }
 
+/// A CAN identifier that has been checked against the bit width implied by
+/// its [`IdentifierFormat`] (11 bits for `Standard`, 29 bits for
+/// `Extended`), so it can no longer be silently truncated like a raw `u32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CanId {
+    value: u32,
+    identifier_format: IdentifierFormat
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanIdError {
+    /// The value has bits set outside the range allowed by the identifier format.
+    InvalidValue
+}
+
+impl CanId {
+    pub fn new(value: u32, identifier_format: IdentifierFormat) -> Result<Self, CanIdError> {
+        let mask = match identifier_format {
+            IdentifierFormat::Standard => STANDARD_MASK,
+            IdentifierFormat::Extended => EXTENDED_MASK
+        };
+
+        if value & !mask != 0 {
+            return Err(CanIdError::InvalidValue);
+        }
+
+        Ok(CanId { value, identifier_format })
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn identifier_format(&self) -> IdentifierFormat {
+        self.identifier_format.clone()
+    }
+}
+
+impl TryFrom<(u32, IdentifierFormat)> for CanId {
+    type Error = CanIdError;
+    fn try_from(value: (u32, IdentifierFormat)) -> Result<Self, Self::Error> {
+        CanId::new(value.0, value.1)
+    }
+}
+
+/// Note: CAN FD (64-byte payloads, BRS/ESI) is out of scope for this enum
+/// model. `DataFrame`'s 8-byte payload and single-nibble dlc are load-bearing
+/// for the classic-CAN Lawicel wire format this crate speaks; extending it
+/// to FD would mean a second, incompatible data/dlc representation rather
+/// than a small addition, so it was dropped instead of attempted here.
 #[derive(Debug)]
 pub enum CanFrame {
     DataFrame(DataFrame),
-    RemoteFrame(RemoteFrame)
+    RemoteFrame(RemoteFrame),
+    ErrorFrame(ErrorFrame)
 }
 
 impl From<DataFrame> for CanFrame {
@@ -51,4 +106,280 @@ impl From<&RemoteFrame> for CanFrame {
         let frame = value.clone();
         CanFrame::RemoteFrame(frame)
     }
+}
+
+impl From<ErrorFrame> for CanFrame {
+    fn from(value: ErrorFrame) -> Self {
+        CanFrame::ErrorFrame(value)
+    }
+}
+
+impl From<&ErrorFrame> for CanFrame {
+    fn from(value: &ErrorFrame) -> Self {
+        let frame = value.clone();
+        CanFrame::ErrorFrame(frame)
+    }
+}
+
+/// Shared accessors implemented by every frame type (`DataFrame`,
+/// `RemoteFrame`, `ErrorFrame`), so callers pattern-matching on a
+/// [`CanFrame`] can treat the three variants uniformly.
+pub trait Frame {
+    fn can_id(&self) -> u32;
+    fn identifier_format(&self) -> IdentifierFormat;
+    fn dlc(&self) -> u8;
+    fn data(&self) -> &[u8];
+    fn timestamp(&self) -> u16;
+}
+
+impl Frame for DataFrame {
+    fn can_id(&self) -> u32 {
+        DataFrame::can_id(self)
+    }
+
+    fn identifier_format(&self) -> IdentifierFormat {
+        DataFrame::identifier_format(self)
+    }
+
+    fn dlc(&self) -> u8 {
+        DataFrame::dlc(self)
+    }
+
+    fn data(&self) -> &[u8] {
+        DataFrame::data(self)
+    }
+
+    fn timestamp(&self) -> u16 {
+        DataFrame::timestamp(self).unwrap_or(0)
+    }
+}
+
+impl Frame for RemoteFrame {
+    fn can_id(&self) -> u32 {
+        RemoteFrame::can_id(self)
+    }
+
+    fn identifier_format(&self) -> IdentifierFormat {
+        RemoteFrame::identifier_format(self)
+    }
+
+    fn dlc(&self) -> u8 {
+        RemoteFrame::dlc(self)
+    }
+
+    fn data(&self) -> &[u8] {
+        RemoteFrame::data(self)
+    }
+
+    fn timestamp(&self) -> u16 {
+        RemoteFrame::timestamp(self).unwrap_or(0)
+    }
+}
+
+impl Frame for ErrorFrame {
+    fn can_id(&self) -> u32 {
+        ErrorFrame::can_id(self)
+    }
+
+    fn identifier_format(&self) -> IdentifierFormat {
+        ErrorFrame::identifier_format(self)
+    }
+
+    fn dlc(&self) -> u8 {
+        ErrorFrame::dlc(self)
+    }
+
+    fn data(&self) -> &[u8] {
+        ErrorFrame::data(self)
+    }
+
+    fn timestamp(&self) -> u16 {
+        ErrorFrame::timestamp(self)
+    }
+}
+
+impl Frame for CanFrame {
+    fn can_id(&self) -> u32 {
+        match self {
+            CanFrame::DataFrame(frame) => frame.can_id(),
+            CanFrame::RemoteFrame(frame) => frame.can_id(),
+            CanFrame::ErrorFrame(frame) => frame.can_id()
+        }
+    }
+
+    fn identifier_format(&self) -> IdentifierFormat {
+        match self {
+            CanFrame::DataFrame(frame) => frame.identifier_format(),
+            CanFrame::RemoteFrame(frame) => frame.identifier_format(),
+            CanFrame::ErrorFrame(frame) => frame.identifier_format()
+        }
+    }
+
+    fn dlc(&self) -> u8 {
+        match self {
+            CanFrame::DataFrame(frame) => frame.dlc(),
+            CanFrame::RemoteFrame(frame) => frame.dlc(),
+            CanFrame::ErrorFrame(frame) => frame.dlc()
+        }
+    }
+
+    fn data(&self) -> &[u8] {
+        match self {
+            CanFrame::DataFrame(frame) => frame.data(),
+            CanFrame::RemoteFrame(frame) => frame.data(),
+            CanFrame::ErrorFrame(frame) => frame.data()
+        }
+    }
+
+    fn timestamp(&self) -> u16 {
+        match self {
+            CanFrame::DataFrame(frame) => frame.timestamp().unwrap_or(0),
+            CanFrame::RemoteFrame(frame) => frame.timestamp().unwrap_or(0),
+            CanFrame::ErrorFrame(frame) => frame.timestamp()
+        }
+    }
+}
+
+/// Bridges [`CanFrame`] onto the standard `embedded-can`/`embedded-hal`
+/// ecosystem so a Lawicel adapter can be driven by code written against a
+/// generic CAN HAL.
+impl embedded_can::Frame for CanFrame {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if data.len() > 8 {
+            return None;
+        }
+
+        let (can_id, identifier_format) = match id.into() {
+            Id::Standard(id) => (id.as_raw() as u32, IdentifierFormat::Standard),
+            Id::Extended(id) => (id.as_raw(), IdentifierFormat::Extended),
+        };
+
+        let frame = data::DataFrameBuilder::new()
+            .can_id(can_id, identifier_format)
+            .dlc(data.len() as u8)
+            .data(data);
+
+        Some(CanFrame::DataFrame(frame.into()))
+    }
+
+    fn new_remote(id: impl Into<Id>, dlc: usize) -> Option<Self> {
+        if dlc > 8 {
+            return None;
+        }
+
+        let can_id = match id.into() {
+            Id::Standard(id) => CanId::new(id.as_raw() as u32, IdentifierFormat::Standard),
+            Id::Extended(id) => CanId::new(id.as_raw(), IdentifierFormat::Extended),
+        }.ok()?;
+
+        let frame = remote::RemoteFrameBuilder::new()
+            .can_id(can_id)
+            .dlc(dlc as u8);
+
+        Some(CanFrame::RemoteFrame(frame.into()))
+    }
+
+    fn is_extended(&self) -> bool {
+        matches!(Frame::identifier_format(self), IdentifierFormat::Extended)
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        matches!(self, CanFrame::RemoteFrame(_))
+    }
+
+    fn id(&self) -> Id {
+        match Frame::identifier_format(self) {
+            IdentifierFormat::Standard => Id::Standard(StandardId::new(Frame::can_id(self) as u16).unwrap()),
+            IdentifierFormat::Extended => Id::Extended(ExtendedId::new(Frame::can_id(self)).unwrap()),
+        }
+    }
+
+    fn dlc(&self) -> usize {
+        Frame::dlc(self) as usize
+    }
+
+    fn data(&self) -> &[u8] {
+        Frame::data(self)
+    }
+}
+
+/// Errors from decoding a [`CanFrame`] off the wire: dispatches on the
+/// command byte to [`DataFrameParseError`] (`t`/`T`) or
+/// [`RemoteFrameParseError`] (`r`/`R`), matching the dispatch
+/// [`CanFrame::try_from`] itself performs.
+#[derive(Debug, PartialEq)]
+pub enum CanFrameParseError {
+    /// The record does not start with a byte any known frame type claims.
+    MessageStartError,
+    DataFrame(DataFrameParseError),
+    RemoteFrame(RemoteFrameParseError)
+}
+
+impl std::fmt::Display for CanFrameParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CanFrameParseError::MessageStartError => write!(f, "record does not start with 't', 'T', 'r' or 'R'"),
+            CanFrameParseError::DataFrame(err) => write!(f, "failed to parse as a data frame: {}", err),
+            CanFrameParseError::RemoteFrame(err) => write!(f, "failed to parse as a remote frame: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for CanFrameParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CanFrameParseError::DataFrame(err) => Some(err),
+            CanFrameParseError::RemoteFrame(err) => Some(err),
+            CanFrameParseError::MessageStartError => None
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for CanFrame {
+    type Error = CanFrameParseError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        match value.first() {
+            Some(b't') | Some(b'T') => DataFrame::try_from(value)
+                .map(CanFrame::DataFrame)
+                .map_err(CanFrameParseError::DataFrame),
+            Some(b'r') | Some(b'R') => RemoteFrame::try_from(value)
+                .map(CanFrame::RemoteFrame)
+                .map_err(CanFrameParseError::RemoteFrame),
+            _ => Err(CanFrameParseError::MessageStartError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_id_rejects_value_outside_standard_range() {
+        assert_eq!(CanId::new(STANDARD_MASK + 1, IdentifierFormat::Standard), Err(CanIdError::InvalidValue));
+        assert!(CanId::new(STANDARD_MASK, IdentifierFormat::Standard).is_ok());
+    }
+
+    #[test]
+    fn can_id_rejects_value_outside_extended_range() {
+        assert_eq!(CanId::new(EXTENDED_MASK + 1, IdentifierFormat::Extended), Err(CanIdError::InvalidValue));
+        assert!(CanId::new(EXTENDED_MASK, IdentifierFormat::Extended).is_ok());
+    }
+
+    #[test]
+    fn can_frame_try_from_dispatches_on_command_byte() {
+        assert!(matches!(CanFrame::try_from(b"t1230\r".as_slice()), Ok(CanFrame::DataFrame(_))));
+        assert!(matches!(CanFrame::try_from(b"r1230\r".as_slice()), Ok(CanFrame::RemoteFrame(_))));
+    }
+
+    #[test]
+    fn can_frame_try_from_rejects_unknown_command() {
+        assert_eq!(CanFrame::try_from(b"x1230\r".as_slice()), Err(CanFrameParseError::MessageStartError));
+    }
+
+    #[test]
+    fn can_frame_try_from_propagates_inner_parse_error() {
+        assert!(matches!(CanFrame::try_from(b"t\r".as_slice()), Err(CanFrameParseError::DataFrame(_))));
+    }
 }
\ No newline at end of file
@@ -1,11 +1,13 @@
-use super::{IdentifierFormat, STANDARD_MASK, EXTENDED_MASK};
+use embedded_can::{ExtendedId, Id, StandardId};
+
+use super::{CanId, IdentifierFormat};
 
 #[derive(Debug)]
 pub struct RemoteFrame {
     can_id: u32,
     identifier_format: IdentifierFormat,
     dlc: u8,
-    timestamp: u16
+    timestamp: Option<u16>
 }
 
 impl RemoteFrame {
@@ -14,10 +16,7 @@ impl RemoteFrame {
     }
 
     pub fn can_id(&self) -> u32 {
-        match self.identifier_format {
-            IdentifierFormat::Standard => self.can_id & STANDARD_MASK,
-            IdentifierFormat::Extended => self.can_id & EXTENDED_MASK
-        }
+        self.can_id
     }
 
     pub fn identifier_format(&self) -> IdentifierFormat {
@@ -36,16 +35,55 @@ impl RemoteFrame {
         &mut []
     }
 
-    pub fn timestamp(&self) -> u16 {
+    /// The millisecond timestamp the frame arrived with, or `None` if the
+    /// record it was decoded from didn't carry one (the adapter's `Z1`
+    /// timestamp mode was off).
+    pub fn timestamp(&self) -> Option<u16> {
         self.timestamp
     }
+
+    /// Replaces the identifier in place, re-validating it against `format`
+    /// the same way [`CanId::new`] does rather than silently truncating it.
+    pub fn set_can_id(&mut self, can_id: u32, format: IdentifierFormat) -> Result<(), super::CanIdError> {
+        let validated = CanId::new(can_id, format)?;
+        self.can_id = validated.value();
+        self.identifier_format = validated.identifier_format();
+        Ok(())
+    }
+
+    /// Replaces the requested data length code in place.
+    pub fn set_dlc(&mut self, dlc: u8) -> Result<(), RemoteFrameDlcError> {
+        if dlc > 8 {
+            return Err(RemoteFrameDlcError::DlcOutOfRange);
+        }
+        self.dlc = dlc;
+        Ok(())
+    }
+}
+
+/// Errors from [`RemoteFrame::set_dlc`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteFrameDlcError {
+    /// The data length code is greater than 8, the most data bytes a
+    /// classic CAN frame carries.
+    DlcOutOfRange
+}
+
+impl std::fmt::Display for RemoteFrameDlcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemoteFrameDlcError::DlcOutOfRange => write!(f, "data length code must be 0..=8"),
+        }
+    }
 }
 
+impl std::error::Error for RemoteFrameDlcError {}
+
 pub struct RemoteFrameBuilder {
     can_id: u32,
     identifier_format: IdentifierFormat,
     dlc: u8,
-    timestamp: u16
+    timestamp: Option<u16>
 }
 
 impl RemoteFrameBuilder {
@@ -54,20 +92,13 @@ impl RemoteFrameBuilder {
             can_id: 0,
             identifier_format: IdentifierFormat::Standard,
             dlc: 0,
-            timestamp: 0
+            timestamp: None
         }
     }
 
-    pub fn can_id(mut self, can_id: u32, format: IdentifierFormat) -> Self {
-        match format {
-            IdentifierFormat::Standard => {
-                self.can_id = can_id & STANDARD_MASK;
-            },
-            IdentifierFormat::Extended => {
-                self.can_id = can_id & EXTENDED_MASK;
-            },
-        };
-        self.identifier_format = format;
+    pub fn can_id(mut self, can_id: CanId) -> Self {
+        self.can_id = can_id.value();
+        self.identifier_format = can_id.identifier_format();
         self
     }
 
@@ -87,3 +118,228 @@ impl From<RemoteFrameBuilder> for RemoteFrame {
         }
     }
 }
+
+impl embedded_can::Frame for RemoteFrame {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if !data.is_empty() {
+            return None;
+        }
+        Self::new_remote(id, 0)
+    }
+
+    fn new_remote(id: impl Into<Id>, dlc: usize) -> Option<Self> {
+        if dlc > 8 {
+            return None;
+        }
+
+        let can_id = match id.into() {
+            Id::Standard(id) => CanId::new(id.as_raw() as u32, IdentifierFormat::Standard),
+            Id::Extended(id) => CanId::new(id.as_raw(), IdentifierFormat::Extended),
+        }.expect("embedded_can already validated the identifier width");
+
+        let builder = RemoteFrameBuilder::new().can_id(can_id);
+
+        Some(builder.dlc(dlc as u8).into())
+    }
+
+    fn is_extended(&self) -> bool {
+        matches!(self.identifier_format, IdentifierFormat::Extended)
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        true
+    }
+
+    fn id(&self) -> Id {
+        match self.identifier_format {
+            IdentifierFormat::Standard => Id::Standard(StandardId::new(self.can_id() as u16).unwrap()),
+            IdentifierFormat::Extended => Id::Extended(ExtendedId::new(self.can_id()).unwrap()),
+        }
+    }
+
+    fn dlc(&self) -> usize {
+        self.dlc as usize
+    }
+
+    fn data(&self) -> &[u8] {
+        self.data()
+    }
+}
+
+/// Errors from decoding a [`RemoteFrame`] off the wire (`r`/`R` records).
+#[derive(Debug, PartialEq)]
+pub enum RemoteFrameParseError {
+    /// The record did not have the length implied by its command byte,
+    /// once an optional 4 hex digit timestamp is accounted for.
+    InvalidSize,
+    MessageStartError,
+    IntegerParseError,
+    Utf8Error,
+    DlcError,
+    MessageTerminationError,
+    /// The identifier does not fit the 11-bit (standard) or 29-bit
+    /// (extended) range implied by the frame's command byte.
+    InvalidIdentifier
+}
+
+impl std::fmt::Display for RemoteFrameParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemoteFrameParseError::InvalidSize => write!(f, "record has an invalid size for a remote frame"),
+            RemoteFrameParseError::MessageStartError => write!(f, "record does not start with 'r' or 'R'"),
+            RemoteFrameParseError::IntegerParseError => write!(f, "record contains a field that is not valid hexadecimal"),
+            RemoteFrameParseError::Utf8Error => write!(f, "record is not valid ASCII/UTF-8"),
+            RemoteFrameParseError::DlcError => write!(f, "record has an invalid data length code"),
+            RemoteFrameParseError::MessageTerminationError => write!(f, "record is not terminated with '\\r'"),
+            RemoteFrameParseError::InvalidIdentifier => write!(f, "identifier exceeds the range allowed by its frame format"),
+        }
+    }
+}
+
+impl std::error::Error for RemoteFrameParseError {}
+
+impl TryFrom<&[u8]> for RemoteFrame {
+    type Error = RemoteFrameParseError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let command = *value.first().ok_or(RemoteFrameParseError::MessageStartError)?;
+        let identifier_format = match command {
+            b'r' => IdentifierFormat::Standard,
+            b'R' => IdentifierFormat::Extended,
+            _ => return Err(RemoteFrameParseError::MessageStartError)
+        };
+
+        let id_width = match identifier_format {
+            IdentifierFormat::Standard => 3,
+            IdentifierFormat::Extended => 8
+        };
+
+        let id_slice = value.get(1..1+id_width).ok_or(RemoteFrameParseError::InvalidSize)?;
+        let id_str = std::str::from_utf8(id_slice).map_err(|_| RemoteFrameParseError::Utf8Error)?;
+        let raw_id = u32::from_str_radix(id_str, 16).map_err(|_| RemoteFrameParseError::IntegerParseError)?;
+        let can_id = CanId::new(raw_id, identifier_format).map_err(|_| RemoteFrameParseError::InvalidIdentifier)?;
+
+        let dlc_index = 1 + id_width;
+        let dlc_byte = *value.get(dlc_index).ok_or(RemoteFrameParseError::InvalidSize)?;
+        let dlc = (dlc_byte as char).to_digit(16).ok_or(RemoteFrameParseError::DlcError)? as u8;
+        if dlc > 8 {
+            return Err(RemoteFrameParseError::DlcError);
+        }
+
+        // layout: command + id + dlc nibble, then an optional trailing 4
+        // hex digit timestamp, then the terminator (remote frames carry no data).
+        let payload_end = dlc_index + 1;
+
+        let terminator_index = value.len().checked_sub(1).ok_or(RemoteFrameParseError::InvalidSize)?;
+        if value[terminator_index] != b'\r' {
+            return Err(RemoteFrameParseError::MessageTerminationError);
+        }
+
+        let residual = terminator_index.checked_sub(payload_end).ok_or(RemoteFrameParseError::InvalidSize)?;
+        let timestamp = match residual {
+            0 => None,
+            4 => {
+                let slice = &value[payload_end..payload_end+4];
+                let text = std::str::from_utf8(slice).map_err(|_| RemoteFrameParseError::Utf8Error)?;
+                Some(u16::from_str_radix(text, 16).map_err(|_| RemoteFrameParseError::IntegerParseError)?)
+            },
+            _ => return Err(RemoteFrameParseError::InvalidSize)
+        };
+
+        Ok(RemoteFrame { can_id: can_id.value(), identifier_format, dlc, timestamp })
+    }
+}
+
+impl std::fmt::Display for RemoteFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.identifier_format {
+            IdentifierFormat::Standard => write!(f, "r{:03X}{:01X}", self.can_id(), self.dlc)?,
+            IdentifierFormat::Extended => write!(f, "R{:08X}{:01X}", self.can_id(), self.dlc)?,
+        }
+
+        if let Some(timestamp) = self.timestamp {
+            write!(f, "{:04X}", timestamp)?;
+        }
+
+        write!(f, "\r")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_can::{Frame as _, StandardId, ExtendedId, Id};
+
+    #[test]
+    fn standard_remote_frame_parses() {
+        let frame = RemoteFrame::try_from(b"r1235\r".as_slice()).unwrap();
+        assert_eq!(frame.can_id(), 0x123);
+        assert_eq!(frame.dlc(), 5);
+        assert_eq!(frame.data(), &[] as &[u8]);
+        assert_eq!(frame.timestamp(), None);
+    }
+
+    #[test]
+    fn extended_remote_frame_with_timestamp_parses() {
+        let frame = RemoteFrame::try_from(b"R01ABCDEF3EA5F\r".as_slice()).unwrap();
+        assert_eq!(frame.can_id(), 0x1ABCDEF);
+        assert_eq!(frame.dlc(), 3);
+        assert_eq!(frame.timestamp(), Some(0xEA5F));
+    }
+
+    #[test]
+    fn try_from_rejects_unknown_command() {
+        assert_eq!(RemoteFrame::try_from(b"x1230\r".as_slice()), Err(RemoteFrameParseError::MessageStartError));
+    }
+
+    #[test]
+    fn try_from_rejects_missing_terminator() {
+        assert_eq!(RemoteFrame::try_from(b"r1230".as_slice()), Err(RemoteFrameParseError::MessageTerminationError));
+    }
+
+    #[test]
+    fn try_from_rejects_dlc_over_eight() {
+        assert_eq!(RemoteFrame::try_from(b"r123F\r".as_slice()), Err(RemoteFrameParseError::DlcError));
+    }
+
+    #[test]
+    fn embedded_can_new_remote_round_trips() {
+        let frame = RemoteFrame::new_remote(Id::Standard(StandardId::new(0x123).unwrap()), 4).unwrap();
+        assert_eq!(frame.can_id(), 0x123);
+        assert_eq!(frame.dlc(), 4);
+        assert!(frame.is_remote_frame());
+        assert!(!frame.is_extended());
+
+        let extended = RemoteFrame::new_remote(Id::Extended(ExtendedId::new(0x1ABCDEF).unwrap()), 2).unwrap();
+        assert!(extended.is_extended());
+    }
+
+    #[test]
+    fn embedded_can_new_rejects_non_empty_data() {
+        assert!(RemoteFrame::new(Id::Standard(StandardId::new(0x123).unwrap()), &[0x01]).is_none());
+    }
+
+    #[test]
+    fn display_round_trips_through_try_from() {
+        let frame: RemoteFrame = RemoteFrame::new().can_id(CanId::new(0x123, IdentifierFormat::Standard).unwrap()).dlc(5).into();
+        let text = frame.to_string();
+        assert_eq!(text, "r1235\r");
+        assert_eq!(RemoteFrame::try_from(text.as_bytes()).unwrap().can_id(), 0x123);
+    }
+
+    #[test]
+    fn set_can_id_validates_against_the_identifier_format() {
+        let mut frame: RemoteFrame = RemoteFrame::new().can_id(CanId::new(0x123, IdentifierFormat::Standard).unwrap()).into();
+        assert_eq!(frame.set_can_id(0x7FF, IdentifierFormat::Standard), Ok(()));
+        assert_eq!(frame.can_id(), 0x7FF);
+        assert!(frame.set_can_id(0x800, IdentifierFormat::Standard).is_err());
+        assert_eq!(frame.can_id(), 0x7FF);
+    }
+
+    #[test]
+    fn set_dlc_rejects_values_over_eight() {
+        let mut frame: RemoteFrame = RemoteFrame::new().can_id(CanId::new(0x123, IdentifierFormat::Standard).unwrap()).dlc(2).into();
+        assert_eq!(frame.set_dlc(9), Err(RemoteFrameDlcError::DlcOutOfRange));
+        assert_eq!(frame.dlc(), 2);
+    }
+}
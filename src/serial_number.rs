@@ -1,102 +1,204 @@
-use std::str::FromStr;
-
-
-#[derive(Debug, PartialEq)]
-pub struct SerialNumber {
-    data: [u8; 4]
-}
-
-impl SerialNumber {
-    pub fn new(value: &str) -> Result<SerialNumber, ()> {
-        let mut serial = SerialNumber {
-            data: [b'\0'; 4]
-        };
-        
-        let buf = value.as_bytes();
-        if value.len() == 4 {
-            serial.data[0] = buf[0];
-            serial.data[1] = buf[1];
-            serial.data[2] = buf[2];
-            serial.data[3] = buf[3];
-            Ok(serial)
-        } else {
-            Err(())
-        }
-    }
-
-    pub fn to_str(&self) -> &str {
-        std::str::from_utf8(&self.data).unwrap_or("")
-    }
-}
-
-#[derive(Debug, PartialEq)]
-pub enum SerialNumberParseError {
-    InvalidSize,
-    MessageStartError,
-    AsciiError,
-    DataError,
-    MessageTerminationError
-}
-
-impl TryFrom<&[u8]> for SerialNumber {
-    type Error = SerialNumberParseError;
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        // check is ascii
-        if !value.is_ascii() {
-            return Err(SerialNumberParseError::AsciiError);
-        }
-
-        // check size
-        if value.len() != 6 {
-            return Err(SerialNumberParseError::InvalidSize);
-        } 
-
-        // check message start
-        match value.get(0) {
-            Some(chr) => {
-                if *chr != b'N' {
-                    return Err(SerialNumberParseError::MessageStartError);
-                }
-            }
-            None => return Err(SerialNumberParseError::MessageStartError)
-        }
-
-        // message termination
-        match value.get(value.len()-1) {
-            Some(chr) => {
-                if *chr != b'\r' {
-                    return Err(SerialNumberParseError::MessageTerminationError);
-                }
-            },
-            None => return Err(SerialNumberParseError::MessageTerminationError)
-        }
-
-        // retrieve serial number
-        return match value.get(1..1+4) {
-            Some(slice) => {
-                if slice.len() == 4 {
-                    match std::str::from_utf8(slice) {
-                        Ok(string) => {
-                            match SerialNumber::new(string) {
-                                Ok(val) => Ok(val),
-                                Err(_) => Err(SerialNumberParseError::DataError),
-                            }
-                        },
-                        Err(_) => Err(SerialNumberParseError::DataError),
-                    }
-                } else {
-                    Err(SerialNumberParseError::DataError)
-                }
-            },
-            None => Err(SerialNumberParseError::DataError),
-        };
-
-    }
-}
-
-impl FromStr for SerialNumber {
-    type Err = SerialNumberParseError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        SerialNumber::try_from(s.as_bytes())
-    }
-}
\ No newline at end of file
+use core::str::FromStr;
+
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq)]
+pub struct SerialNumber {
+    data: [u8; 4]
+}
+
+impl SerialNumber {
+    pub fn new(value: &str) -> Result<SerialNumber, ()> {
+        let mut serial = SerialNumber {
+            data: [b'\0'; 4]
+        };
+
+        let buf = value.as_bytes();
+        if value.len() == 4 {
+            serial.data[0] = buf[0];
+            serial.data[1] = buf[1];
+            serial.data[2] = buf[2];
+            serial.data[3] = buf[3];
+            Ok(serial)
+        } else {
+            Err(())
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        core::str::from_utf8(&self.data).unwrap_or("")
+    }
+}
+
+/// Serializes as the 4-character string form (e.g. `"1234"`), not the raw
+/// byte array, so the encoded output is self-describing.
+#[cfg(feature = "serde")]
+impl serde::Serialize for SerialNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        serializer.serialize_str(self.to_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+struct SerialNumberVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for SerialNumberVisitor {
+    type Value = SerialNumber;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a 4 character serial number")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where E: serde::de::Error {
+        SerialNumber::new(value).map_err(|_| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Deserialization is routed through [`SerialNumber::new`] so the 4-byte
+/// length invariant cannot be bypassed via a crafted payload.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SerialNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        deserializer.deserialize_str(SerialNumberVisitor)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, PartialEq)]
+pub enum SerialNumberParseError {
+    /// The record did not have the 6 bytes a serial number reply requires;
+    /// carries the length that was actually received.
+    InvalidLength(usize),
+    MessageStartError,
+    /// The record contained a non-ASCII or otherwise unexpected byte;
+    /// carries the offending byte and its index within the record.
+    InvalidCharacter(u8, usize),
+    DataError,
+    MessageTerminationError
+}
+
+impl core::fmt::Display for SerialNumberParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SerialNumberParseError::InvalidLength(len) => write!(f, "expected a 6 byte serial number record, found {} byte(s)", len),
+            SerialNumberParseError::MessageStartError => write!(f, "serial number record does not start with 'N'"),
+            SerialNumberParseError::InvalidCharacter(chr, pos) => write!(f, "unexpected character {:#04x} at position {}", chr, pos),
+            SerialNumberParseError::DataError => write!(f, "serial number field does not hold a valid serial number"),
+            SerialNumberParseError::MessageTerminationError => write!(f, "serial number record is not terminated with '\\r'"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SerialNumberParseError {}
+
+impl TryFrom<&[u8]> for SerialNumber {
+    type Error = SerialNumberParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        // check is ascii
+        if let Some((pos, chr)) = value.iter().enumerate().find(|(_, chr)| !chr.is_ascii()) {
+            return Err(SerialNumberParseError::InvalidCharacter(*chr, pos));
+        }
+
+        // check size
+        if value.len() != 6 {
+            return Err(SerialNumberParseError::InvalidLength(value.len()));
+        }
+
+        // check message start
+        match value.get(0) {
+            Some(chr) => {
+                if *chr != b'N' {
+                    return Err(SerialNumberParseError::MessageStartError);
+                }
+            }
+            None => return Err(SerialNumberParseError::MessageStartError)
+        }
+
+        // message termination
+        match value.get(value.len()-1) {
+            Some(chr) => {
+                if *chr != b'\r' {
+                    return Err(SerialNumberParseError::MessageTerminationError);
+                }
+            },
+            None => return Err(SerialNumberParseError::MessageTerminationError)
+        }
+
+        // retrieve serial number
+        return match value.get(1..1+4) {
+            Some(slice) => {
+                if slice.len() == 4 {
+                    match core::str::from_utf8(slice) {
+                        Ok(string) => {
+                            match SerialNumber::new(string) {
+                                Ok(val) => Ok(val),
+                                Err(_) => Err(SerialNumberParseError::DataError),
+                            }
+                        },
+                        Err(_) => Err(SerialNumberParseError::DataError),
+                    }
+                } else {
+                    Err(SerialNumberParseError::DataError)
+                }
+            },
+            None => Err(SerialNumberParseError::DataError),
+        };
+
+    }
+}
+
+impl FromStr for SerialNumber {
+    type Err = SerialNumberParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SerialNumber::try_from(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_accepts_four_characters() {
+        let serial = SerialNumber::new("1234").unwrap();
+        assert_eq!(serial.to_str(), "1234");
+    }
+
+    #[test]
+    fn new_rejects_wrong_length() {
+        assert!(SerialNumber::new("123").is_err());
+        assert!(SerialNumber::new("12345").is_err());
+    }
+
+    #[test]
+    fn parses_record() {
+        let serial = SerialNumber::try_from(b"N1234\r".as_slice()).unwrap();
+        assert_eq!(serial.to_str(), "1234");
+    }
+
+    #[test]
+    fn rejects_wrong_length_record() {
+        assert_eq!(SerialNumber::try_from(b"N123\r".as_slice()), Err(SerialNumberParseError::InvalidLength(5)));
+    }
+
+    #[test]
+    fn rejects_wrong_start_byte() {
+        assert_eq!(SerialNumber::try_from(b"X1234\r".as_slice()), Err(SerialNumberParseError::MessageStartError));
+    }
+
+    #[test]
+    fn rejects_missing_terminator() {
+        assert_eq!(SerialNumber::try_from(b"N1234X".as_slice()), Err(SerialNumberParseError::MessageTerminationError));
+    }
+
+    #[test]
+    fn from_str_matches_try_from() {
+        let serial: SerialNumber = "N5678\r".parse().unwrap();
+        assert_eq!(serial.to_str(), "5678");
+    }
+}
@@ -1,4 +1,85 @@
 
+/// Hardware/software version reported by the adapter's `V` command.
+///
+/// Requires an uppercase `V` command byte; a reply starting with lowercase
+/// `v` is rejected with [`VersionParseError::MessageStartError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Version {
+    hardware: u8,
+    software: u8
+}
+
+impl Version {
+    pub fn hardware(&self) -> u8 {
+        self.hardware
+    }
+
+    pub fn software(&self) -> u8 {
+        self.software
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum VersionParseError {
+    /// The record did not have the 6 bytes a version reply requires;
+    /// carries the length that was actually received.
+    InvalidLength(usize),
+    MessageStartError,
+    /// The record contained a non-ASCII or otherwise unexpected byte;
+    /// carries the offending byte and its index within the record.
+    InvalidCharacter(u8, usize),
+    MessageTerminationError
+}
+
+impl std::fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionParseError::InvalidLength(len) => write!(f, "expected a 6 byte version record, found {} byte(s)", len),
+            VersionParseError::MessageStartError => write!(f, "version record does not start with 'V'"),
+            VersionParseError::InvalidCharacter(chr, pos) => write!(f, "unexpected character {:#04x} at position {}", chr, pos),
+            VersionParseError::MessageTerminationError => write!(f, "version record is not terminated with '\\r'"),
+        }
+    }
+}
+
+impl std::error::Error for VersionParseError {}
+
+impl TryFrom<&[u8]> for Version {
+    type Error = VersionParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if let Some((pos, chr)) = value.iter().enumerate().find(|(_, chr)| !chr.is_ascii()) {
+            return Err(VersionParseError::InvalidCharacter(*chr, pos));
+        }
+
+        if value.len() != 6 {
+            return Err(VersionParseError::InvalidLength(value.len()));
+        }
+
+        if value[0] != b'V' {
+            return Err(VersionParseError::MessageStartError);
+        }
+
+        if value[5] != b'\r' {
+            return Err(VersionParseError::MessageTerminationError);
+        }
+
+        if let Some((pos, chr)) = value[1..5].iter().enumerate().find(|(_, chr)| !chr.is_ascii_hexdigit()) {
+            return Err(VersionParseError::InvalidCharacter(*chr, pos + 1));
+        }
+
+        let hardware = u8::from_str_radix(std::str::from_utf8(&value[1..3]).unwrap(), 16).unwrap();
+        let software = u8::from_str_radix(std::str::from_utf8(&value[3..5]).unwrap(), 16).unwrap();
+        Ok(Version { hardware, software })
+    }
+}
+
+impl std::str::FromStr for Version {
+    type Err = VersionParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Version::try_from(s.as_bytes())
+    }
+}
+
 #[derive(Debug)]
 pub struct Status {
     pub(crate) status: u8
@@ -75,3 +156,144 @@ impl From<u8> for Status {
         }
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub enum StatusParseError {
+    /// The record did not have the 4 bytes a status reply requires;
+    /// carries the length that was actually received.
+    InvalidLength(usize),
+    MessageStartError,
+    /// The record contained a non-ASCII or otherwise unexpected byte;
+    /// carries the offending byte and its index within the record.
+    InvalidCharacter(u8, usize),
+    MessageTerminationError
+}
+
+impl std::fmt::Display for StatusParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatusParseError::InvalidLength(len) => write!(f, "expected a 4 byte status record, found {} byte(s)", len),
+            StatusParseError::MessageStartError => write!(f, "status record does not start with 'F'"),
+            StatusParseError::InvalidCharacter(chr, pos) => write!(f, "unexpected character {:#04x} at position {}", chr, pos),
+            StatusParseError::MessageTerminationError => write!(f, "status record is not terminated with '\\r'"),
+        }
+    }
+}
+
+impl std::error::Error for StatusParseError {}
+
+impl TryFrom<&[u8]> for Status {
+    type Error = StatusParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if let Some((pos, chr)) = value.iter().enumerate().find(|(_, chr)| !chr.is_ascii()) {
+            return Err(StatusParseError::InvalidCharacter(*chr, pos));
+        }
+
+        if value.len() != 4 {
+            return Err(StatusParseError::InvalidLength(value.len()));
+        }
+
+        if value[0] != b'F' {
+            return Err(StatusParseError::MessageStartError);
+        }
+
+        if value[3] != b'\r' {
+            return Err(StatusParseError::MessageTerminationError);
+        }
+
+        if let Some((pos, chr)) = value[1..3].iter().enumerate().find(|(_, chr)| !chr.is_ascii_hexdigit()) {
+            return Err(StatusParseError::InvalidCharacter(*chr, pos + 1));
+        }
+
+        let status = u8::from_str_radix(std::str::from_utf8(&value[1..3]).unwrap(), 16).unwrap();
+        Ok(Status { status })
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = StatusParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Status::try_from(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_flag_bit() {
+        let status = Status::try_from(b"FFF\r".as_slice()).unwrap();
+        assert!(status.receive_fifo_is_full());
+        assert!(status.transmit_fifo_is_full());
+        assert!(status.error_warning());
+        assert!(status.data_overrun());
+        assert!(status.error_passive());
+        assert!(status.arbitration_lost());
+        assert!(status.bus_error());
+    }
+
+    #[test]
+    fn parses_no_flags_set() {
+        let status = Status::try_from(b"F00\r".as_slice()).unwrap();
+        assert!(!status.receive_fifo_is_full());
+        assert!(!status.bus_error());
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        assert_eq!(Status::try_from(b"F0\r".as_slice()), Err(StatusParseError::InvalidLength(3)));
+    }
+
+    #[test]
+    fn rejects_wrong_start_byte() {
+        assert_eq!(Status::try_from(b"X00\r".as_slice()), Err(StatusParseError::MessageStartError));
+    }
+
+    #[test]
+    fn rejects_missing_terminator() {
+        assert_eq!(Status::try_from(b"F00X".as_slice()), Err(StatusParseError::MessageTerminationError));
+    }
+
+    #[test]
+    fn round_trips_through_u8() {
+        let status: Status = 0x5Au8.into();
+        let value: u8 = status.into();
+        assert_eq!(value, 0x5A);
+    }
+
+    #[test]
+    fn from_str_matches_try_from() {
+        let status: Status = "F01\r".parse().unwrap();
+        assert!(status.receive_fifo_is_full());
+    }
+
+    #[test]
+    fn version_parses_hardware_and_software_bytes() {
+        let version = Version::try_from(b"V0102\r".as_slice()).unwrap();
+        assert_eq!(version.hardware(), 0x01);
+        assert_eq!(version.software(), 0x02);
+    }
+
+    #[test]
+    fn version_rejects_wrong_length() {
+        assert_eq!(Version::try_from(b"V01\r".as_slice()), Err(VersionParseError::InvalidLength(4)));
+    }
+
+    #[test]
+    fn version_rejects_wrong_start_byte() {
+        assert_eq!(Version::try_from(b"X0102\r".as_slice()), Err(VersionParseError::MessageStartError));
+    }
+
+    #[test]
+    fn version_rejects_missing_terminator() {
+        assert_eq!(Version::try_from(b"V0102X".as_slice()), Err(VersionParseError::MessageTerminationError));
+    }
+
+    #[test]
+    fn version_from_str_matches_try_from() {
+        let version: Version = "VAB12\r".parse().unwrap();
+        assert_eq!(version.hardware(), 0xAB);
+        assert_eq!(version.software(), 0x12);
+    }
+}